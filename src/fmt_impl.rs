@@ -0,0 +1,197 @@
+//! Zero-allocation formatting adapters for [`Uuid7`].
+//!
+//! Each adapter wraps a `Uuid7` by value and writes directly into a
+//! caller-provided, fixed-size buffer, so formatting never touches the
+//! heap. Obtain one via [`Uuid7::hyphenated`], [`Uuid7::simple`],
+//! [`Uuid7::urn`], or [`Uuid7::braced`].
+
+use crate::Uuid7;
+use core::fmt;
+use core::str;
+
+const HEX_LOWER: &[u8; 16] = b"0123456789abcdef";
+const HEX_UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Byte ranges of the five hyphen-separated groups in the canonical
+/// 8-4-4-4-12 layout.
+const GROUPS: [(usize, usize); 5] = [(0, 4), (4, 6), (6, 8), (8, 10), (10, 16)];
+
+fn encode_simple<'a>(
+    bytes: &[u8; 16],
+    buf: &'a mut [u8; Simple::LENGTH],
+    upper: bool,
+) -> &'a mut str {
+    let table = if upper { HEX_UPPER } else { HEX_LOWER };
+    for (i, &byte) in bytes.iter().enumerate() {
+        buf[i * 2] = table[(byte >> 4) as usize];
+        buf[i * 2 + 1] = table[(byte & 0x0f) as usize];
+    }
+    // SAFETY: every byte written above is an ASCII hex digit.
+    unsafe { str::from_utf8_unchecked_mut(buf) }
+}
+
+fn encode_hyphenated<'a>(
+    bytes: &[u8; 16],
+    buf: &'a mut [u8; Hyphenated::LENGTH],
+    upper: bool,
+) -> &'a mut str {
+    let table = if upper { HEX_UPPER } else { HEX_LOWER };
+    let mut pos = 0;
+    for (i, &(start, end)) in GROUPS.iter().enumerate() {
+        for &byte in &bytes[start..end] {
+            buf[pos] = table[(byte >> 4) as usize];
+            buf[pos + 1] = table[(byte & 0x0f) as usize];
+            pos += 2;
+        }
+        if i != GROUPS.len() - 1 {
+            buf[pos] = b'-';
+            pos += 1;
+        }
+    }
+    // SAFETY: every byte written above is either an ASCII hex digit or '-'.
+    unsafe { str::from_utf8_unchecked_mut(buf) }
+}
+
+/// The hyphenated `8-4-4-4-12` form, e.g.
+/// `0184e1a0-7e2a-7d40-8f3b-5c1a2b3c4d5e`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Hyphenated(Uuid7);
+
+impl Hyphenated {
+    /// The length in bytes of a hyphenated UUID string.
+    pub const LENGTH: usize = 36;
+
+    pub(crate) const fn from_uuid(uuid: Uuid7) -> Self {
+        Self(uuid)
+    }
+
+    /// Writes the lowercase hyphenated form into `buf` and returns it as a
+    /// `str`, without allocating.
+    pub fn encode_lower<'a>(&self, buf: &'a mut [u8; Self::LENGTH]) -> &'a mut str {
+        encode_hyphenated(self.0.as_bytes(), buf, false)
+    }
+
+    /// Writes the uppercase hyphenated form into `buf` and returns it as a
+    /// `str`, without allocating.
+    pub fn encode_upper<'a>(&self, buf: &'a mut [u8; Self::LENGTH]) -> &'a mut str {
+        encode_hyphenated(self.0.as_bytes(), buf, true)
+    }
+}
+
+impl fmt::Display for Hyphenated {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0u8; Self::LENGTH];
+        f.write_str(self.encode_lower(&mut buf))
+    }
+}
+
+/// The dashless 32 hex-character form, e.g.
+/// `0184e1a07e2a7d408f3b5c1a2b3c4d5e`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Simple(Uuid7);
+
+impl Simple {
+    /// The length in bytes of a simple (dashless) UUID string.
+    pub const LENGTH: usize = 32;
+
+    pub(crate) const fn from_uuid(uuid: Uuid7) -> Self {
+        Self(uuid)
+    }
+
+    /// Writes the lowercase simple form into `buf` and returns it as a
+    /// `str`, without allocating.
+    pub fn encode_lower<'a>(&self, buf: &'a mut [u8; Self::LENGTH]) -> &'a mut str {
+        encode_simple(self.0.as_bytes(), buf, false)
+    }
+
+    /// Writes the uppercase simple form into `buf` and returns it as a
+    /// `str`, without allocating.
+    pub fn encode_upper<'a>(&self, buf: &'a mut [u8; Self::LENGTH]) -> &'a mut str {
+        encode_simple(self.0.as_bytes(), buf, true)
+    }
+}
+
+impl fmt::Display for Simple {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0u8; Self::LENGTH];
+        f.write_str(self.encode_lower(&mut buf))
+    }
+}
+
+/// The URN form, e.g.
+/// `urn:uuid:0184e1a0-7e2a-7d40-8f3b-5c1a2b3c4d5e`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Urn(Uuid7);
+
+impl Urn {
+    /// The length in bytes of a URN-prefixed UUID string.
+    pub const LENGTH: usize = 45;
+
+    const PREFIX: &'static [u8; 9] = b"urn:uuid:";
+
+    pub(crate) const fn from_uuid(uuid: Uuid7) -> Self {
+        Self(uuid)
+    }
+
+    /// Writes the lowercase URN form into `buf` and returns it as a `str`,
+    /// without allocating.
+    pub fn encode_lower<'a>(&self, buf: &'a mut [u8; Self::LENGTH]) -> &'a mut str {
+        buf[..9].copy_from_slice(Self::PREFIX);
+        encode_hyphenated(self.0.as_bytes(), (&mut buf[9..]).try_into().unwrap(), false);
+        unsafe { str::from_utf8_unchecked_mut(buf) }
+    }
+
+    /// Writes the uppercase URN form into `buf` and returns it as a `str`,
+    /// without allocating.
+    pub fn encode_upper<'a>(&self, buf: &'a mut [u8; Self::LENGTH]) -> &'a mut str {
+        buf[..9].copy_from_slice(Self::PREFIX);
+        encode_hyphenated(self.0.as_bytes(), (&mut buf[9..]).try_into().unwrap(), true);
+        unsafe { str::from_utf8_unchecked_mut(buf) }
+    }
+}
+
+impl fmt::Display for Urn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0u8; Self::LENGTH];
+        f.write_str(self.encode_lower(&mut buf))
+    }
+}
+
+/// The braced form, e.g.
+/// `{0184e1a0-7e2a-7d40-8f3b-5c1a2b3c4d5e}`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Braced(Uuid7);
+
+impl Braced {
+    /// The length in bytes of a braced UUID string.
+    pub const LENGTH: usize = 38;
+
+    pub(crate) const fn from_uuid(uuid: Uuid7) -> Self {
+        Self(uuid)
+    }
+
+    /// Writes the lowercase braced form into `buf` and returns it as a
+    /// `str`, without allocating.
+    pub fn encode_lower<'a>(&self, buf: &'a mut [u8; Self::LENGTH]) -> &'a mut str {
+        buf[0] = b'{';
+        encode_hyphenated(self.0.as_bytes(), (&mut buf[1..37]).try_into().unwrap(), false);
+        buf[37] = b'}';
+        unsafe { str::from_utf8_unchecked_mut(buf) }
+    }
+
+    /// Writes the uppercase braced form into `buf` and returns it as a
+    /// `str`, without allocating.
+    pub fn encode_upper<'a>(&self, buf: &'a mut [u8; Self::LENGTH]) -> &'a mut str {
+        buf[0] = b'{';
+        encode_hyphenated(self.0.as_bytes(), (&mut buf[1..37]).try_into().unwrap(), true);
+        buf[37] = b'}';
+        unsafe { str::from_utf8_unchecked_mut(buf) }
+    }
+}
+
+impl fmt::Display for Braced {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0u8; Self::LENGTH];
+        f.write_str(self.encode_lower(&mut buf))
+    }
+}
@@ -15,6 +15,8 @@
 //! ## Example
 //!
 //! ```rust
+//! # #[cfg(feature = "std")]
+//! # fn main() {
 //! use leachuuid7::Uuid7;
 //!
 //! let uuid = Uuid7::new();
@@ -23,12 +25,49 @@
 //! // Parsing from a string validates the version and variant fields.
 //! let parsed: Uuid7 = "0184e1a0-7e2a-7d40-8f3b-5c1a2b3c4d5e".parse()
 //! .expect("Failed to parse UUIDv7");
+//! # }
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
 //! ```
+//!
+//! ## Features
+//!
+//! - `std` (on by default): enables `Uuid7::new`, `Uuid7::new_with_rng`,
+//!   `Uuid7::new_with_system_time`, `Uuid7::timestamp`, and
+//!   [`Uuid7Generator`], all of which need the system clock and/or a
+//!   thread-safe lock. With `std` disabled, the crate is `no_std`:
+//!   formatting, parsing, the byte/int constructors, and
+//!   `Uuid7::timestamp_millis` all still work, and generation is done via
+//!   `Uuid7::new_with_timestamp` with an explicit millisecond timestamp
+//!   and RNG.
+//! - `getrandom` (off by default): adds `Uuid7::new_with_timestamp_getrandom`,
+//!   a generation path backed directly by the `getrandom` crate instead of
+//!   `rand`, for embedded/WASM targets that want to avoid the full `rand`
+//!   dependency stack.
+//! - `serde` (off by default): implements `Serialize`/`Deserialize` for
+//!   [`Uuid7`], plus a [`compact`] `serde(with = "...")` module for
+//!   binary formats.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
+extern crate std;
 
+use core::fmt;
+use core::str::FromStr;
 use rand::Rng;
-use std::fmt;
-use std::str::FromStr;
-use std::time::{SystemTime, UNIX_EPOCH};
+#[cfg(feature = "std")]
+use std::sync::Mutex;
+#[cfg(feature = "std")]
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+mod fmt_impl;
+pub use fmt_impl::{Braced, Hyphenated, Simple, Urn};
+
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "serde")]
+pub use serde_impl::compact;
 
 /// A UUIDv7 value.
 ///
@@ -39,9 +78,24 @@ pub struct Uuid7 {
     bytes: [u8; 16],
 }
 
+/// Writes the 48-bit Unix-millis timestamp into the first 6 bytes of a
+/// UUIDv7's byte representation.
+fn write_timestamp(bytes: &mut [u8; 16], millis: u64) {
+    bytes[0] = (millis >> 40) as u8;
+    bytes[1] = (millis >> 32) as u8;
+    bytes[2] = (millis >> 24) as u8;
+    bytes[3] = (millis >> 16) as u8;
+    bytes[4] = (millis >> 8) as u8;
+    bytes[5] = millis as u8;
+}
+
 impl Uuid7 {
     /// Generates a new UUIDv7 using the default random number generator.
     ///
+    /// Requires the `std` feature (enabled by default), since it reads the
+    /// system clock. In `no_std` contexts, use [`Uuid7::new_with_timestamp`]
+    /// with an explicit timestamp instead.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -49,12 +103,16 @@ impl Uuid7 {
     /// let uuid = Uuid7::new();
     /// println!("{}", uuid);
     /// ```
+    #[cfg(feature = "std")]
     pub fn new() -> Self {
         Self::new_with_rng(&mut rand::rng())
     }
 
     /// Generates a new UUIDv7 using a custom random number generator.
     ///
+    /// Requires the `std` feature (enabled by default), since it reads the
+    /// system clock.
+    ///
     /// # Example
     ///
     /// ```ignore
@@ -66,35 +124,129 @@ impl Uuid7 {
     /// let uuid = Uuid7::new_with_rng(&mut rng);
     /// println!("{}", uuid);
     /// ```
+    #[cfg(feature = "std")]
     pub fn new_with_rng<R: Rng + ?Sized>(rng: &mut R) -> Self {
-        let mut bytes = [0u8; 16];
-        
-        // Get timestamp as milliseconds since epoch
         let now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .expect("Time went backwards");
-        let millis = now.as_millis() as u64;
-        
-        // First 48 bits: timestamp (6 bytes)
-        bytes[0] = (millis >> 40) as u8;
-        bytes[1] = (millis >> 32) as u8;
-        bytes[2] = (millis >> 24) as u8;
-        bytes[3] = (millis >> 16) as u8;
-        bytes[4] = (millis >> 8) as u8;
-        bytes[5] = millis as u8;
-        
+        Self::new_with_timestamp(now.as_millis() as u64, rng)
+    }
+
+    /// Generates a UUIDv7 for an explicit Unix-millis timestamp instead of
+    /// the current time, e.g. for backfilling historical records or for
+    /// reproducible tests. Available without the `std` feature, since the
+    /// caller supplies the clock reading.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use leachuuid7::Uuid7;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::SmallRng;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(42);
+    /// let uuid = Uuid7::new_with_timestamp(1_718_000_000_000, &mut rng);
+    /// assert_eq!(uuid.timestamp_millis(), 1_718_000_000_000);
+    /// ```
+    pub fn new_with_timestamp<R: Rng + ?Sized>(millis: u64, rng: &mut R) -> Self {
+        let mut bytes = [0u8; 16];
+        write_timestamp(&mut bytes, millis);
+
         // Fill remaining bytes with random data
         rng.fill_bytes(&mut bytes[6..]);
-        
+
         // Set version (7) in the most significant 4 bits of the 7th byte
         bytes[6] = (bytes[6] & 0x0F) | 0x70;
-        
+
         // Set variant (binary 10xx) in the most significant 2 bits of the 9th byte
         bytes[8] = (bytes[8] & 0x3F) | 0x80;
-        
+
+        Self { bytes }
+    }
+
+    /// Generates a UUIDv7 for an explicit [`SystemTime`] instead of the
+    /// current time. Panics if `time` is before the Unix epoch.
+    ///
+    /// Requires the `std` feature (enabled by default).
+    #[cfg(feature = "std")]
+    pub fn new_with_system_time<R: Rng + ?Sized>(time: SystemTime, rng: &mut R) -> Self {
+        let millis = time
+            .duration_since(UNIX_EPOCH)
+            .expect("SystemTime must be at or after the Unix epoch")
+            .as_millis() as u64;
+        Self::new_with_timestamp(millis, rng)
+    }
+
+    /// Generates a UUIDv7 for an explicit Unix-millis timestamp using the
+    /// system entropy source via the `getrandom` crate, bypassing `rand`
+    /// entirely. Useful on embedded/WASM targets that want to avoid the
+    /// full `rand` dependency stack.
+    ///
+    /// Requires the `getrandom` feature.
+    #[cfg(feature = "getrandom")]
+    pub fn new_with_timestamp_getrandom(millis: u64) -> Result<Self, getrandom::Error> {
+        let mut bytes = [0u8; 16];
+        write_timestamp(&mut bytes, millis);
+
+        getrandom::fill(&mut bytes[6..])?;
+
+        bytes[6] = (bytes[6] & 0x0F) | 0x70;
+        bytes[8] = (bytes[8] & 0x3F) | 0x80;
+
+        Ok(Self { bytes })
+    }
+
+    /// Builds a `Uuid7` directly from its 16-byte representation, e.g. one
+    /// read back from a database column or other storage.
+    ///
+    /// This does *not* validate the version/variant nibbles — use this for
+    /// zero-cost round-tripping of bytes already known to be a valid
+    /// UUIDv7. For untrusted input, use [`Uuid7::try_from_bytes`].
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
         Self { bytes }
     }
 
+    /// Builds a `Uuid7` from its 16-byte representation, validating that
+    /// the version and variant nibbles match UUIDv7.
+    pub fn try_from_bytes(bytes: [u8; 16]) -> Result<Self, ParseUuid7Error> {
+        validate_version_variant(&bytes)?;
+        Ok(Self { bytes })
+    }
+
+    /// Builds a `Uuid7` directly from a `u128`, e.g. one read back from a
+    /// database column. Does not validate the version/variant nibbles;
+    /// see [`Uuid7::from_bytes`].
+    pub const fn from_u128(value: u128) -> Self {
+        Self {
+            bytes: value.to_be_bytes(),
+        }
+    }
+
+    /// Builds a `Uuid7` from a byte slice, validating that it is exactly
+    /// 16 bytes long.
+    pub fn from_slice(slice: &[u8]) -> Result<Self, ParseUuid7Error> {
+        let bytes: [u8; 16] = slice.try_into().map_err(|_| ParseUuid7Error::InvalidLength {
+            expected: "16 bytes",
+            actual: slice.len(),
+        })?;
+        Ok(Self::from_bytes(bytes))
+    }
+
+    /// The nil UUID, `00000000-0000-0000-0000-000000000000`.
+    pub const fn nil() -> Self {
+        Self::from_bytes([0u8; 16])
+    }
+
+    /// The max UUID, `ffffffff-ffff-ffff-ffff-ffffffffffff`.
+    pub const fn max() -> Self {
+        Self::from_bytes([0xffu8; 16])
+    }
+
+    /// Returns `true` if this is the nil UUID.
+    pub fn is_nil(&self) -> bool {
+        self.bytes == [0u8; 16]
+    }
+
     /// Returns the inner byte representation.
     pub fn as_bytes(&self) -> &[u8; 16] {
         &self.bytes
@@ -108,93 +260,399 @@ impl Uuid7 {
         }
         value
     }
+
+    /// Reconstructs the embedded Unix timestamp, in milliseconds, from the
+    /// first 48 bits of this UUID.
+    pub fn timestamp_millis(&self) -> u64 {
+        (self.bytes[0] as u64) << 40
+            | (self.bytes[1] as u64) << 32
+            | (self.bytes[2] as u64) << 24
+            | (self.bytes[3] as u64) << 16
+            | (self.bytes[4] as u64) << 8
+            | (self.bytes[5] as u64)
+    }
+
+    /// Reconstructs the embedded timestamp as a [`SystemTime`].
+    ///
+    /// Requires the `std` feature (enabled by default).
+    #[cfg(feature = "std")]
+    pub fn timestamp(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis(self.timestamp_millis())
+    }
+
+    /// Returns a zero-allocation hyphenated (`8-4-4-4-12`) formatting
+    /// adapter for this UUID.
+    pub const fn hyphenated(&self) -> Hyphenated {
+        Hyphenated::from_uuid(*self)
+    }
+
+    /// Returns a zero-allocation simple (dashless, 32 hex chars) formatting
+    /// adapter for this UUID.
+    pub const fn simple(&self) -> Simple {
+        Simple::from_uuid(*self)
+    }
+
+    /// Returns a zero-allocation URN (`urn:uuid:...`) formatting adapter
+    /// for this UUID.
+    pub const fn urn(&self) -> Urn {
+        Urn::from_uuid(*self)
+    }
+
+    /// Returns a zero-allocation braced (`{...}`) formatting adapter for
+    /// this UUID.
+    pub const fn braced(&self) -> Braced {
+        Braced::from_uuid(*self)
+    }
+
+    /// Writes the lowercase canonical hyphenated form into `buf` and
+    /// returns it as a `str`, without allocating.
+    pub fn encode_lower<'a>(&self, buf: &'a mut [u8; Hyphenated::LENGTH]) -> &'a mut str {
+        self.hyphenated().encode_lower(buf)
+    }
+
+    /// Writes the uppercase canonical hyphenated form into `buf` and
+    /// returns it as a `str`, without allocating.
+    pub fn encode_upper<'a>(&self, buf: &'a mut [u8; Hyphenated::LENGTH]) -> &'a mut str {
+        self.hyphenated().encode_upper(buf)
+    }
+
+    /// Builds a UUIDv7 from an explicit timestamp and a 12-bit monotonic
+    /// counter, following the RFC 9562 "fixed bit-length dedicated counter"
+    /// (method 1) layout: the counter occupies the bits immediately after the version
+    /// nibble, and the remainder of the random section is re-randomized on
+    /// every call.
+    #[cfg(feature = "std")]
+    fn from_parts_with_counter<R: Rng + ?Sized>(millis: u64, counter: u16, rng: &mut R) -> Self {
+        let mut bytes = [0u8; 16];
+
+        bytes[0] = (millis >> 40) as u8;
+        bytes[1] = (millis >> 32) as u8;
+        bytes[2] = (millis >> 24) as u8;
+        bytes[3] = (millis >> 16) as u8;
+        bytes[4] = (millis >> 8) as u8;
+        bytes[5] = millis as u8;
+
+        // 12-bit counter: top 4 bits in byte 6 (below the version nibble),
+        // remaining 8 bits in byte 7.
+        bytes[6] = 0x70 | ((counter >> 8) as u8 & 0x0F);
+        bytes[7] = counter as u8;
+
+        // Re-randomize the rest of the random section on every call.
+        rng.fill_bytes(&mut bytes[8..]);
+        bytes[8] = (bytes[8] & 0x3F) | 0x80;
+
+        Self { bytes }
+    }
+}
+
+/// The width of the monotonic counter embedded in the random section,
+/// per RFC 9562's "Fixed-Length Dedicated Counter" guidance.
+#[cfg(feature = "std")]
+const COUNTER_BITS: u32 = 12;
+#[cfg(feature = "std")]
+const COUNTER_MAX: u16 = (1 << COUNTER_BITS) - 1;
+
+/// A stateful UUIDv7 generator that guarantees strictly increasing,
+/// collision-free output for IDs minted on the same node, even when
+/// several are generated within the same millisecond.
+///
+/// It implements the monotonic-counter method from RFC 9562: a 12-bit
+/// counter is seeded into the random section, incremented on every call
+/// that lands in the same millisecond as the previous one, and reseeded
+/// with fresh random bits whenever the clock advances.
+///
+/// Requires the `std` feature (enabled by default), since it needs the
+/// system clock and a thread-safe lock.
+///
+/// # Example
+///
+/// ```rust
+/// use leachuuid7::Uuid7Generator;
+///
+/// let generator = Uuid7Generator::new();
+/// let a = generator.generate();
+/// let b = generator.generate();
+/// assert!(a < b);
+/// ```
+#[cfg(feature = "std")]
+pub struct Uuid7Generator {
+    state: Mutex<GeneratorState>,
+}
+
+#[cfg(feature = "std")]
+struct GeneratorState {
+    last_millis: u64,
+    counter: u16,
+}
+
+#[cfg(feature = "std")]
+impl Uuid7Generator {
+    /// Creates a new generator with no prior state.
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(GeneratorState {
+                last_millis: 0,
+                counter: 0,
+            }),
+        }
+    }
+
+    /// Generates a new monotonic UUIDv7 using the default random number
+    /// generator.
+    pub fn generate(&self) -> Uuid7 {
+        self.generate_with_rng(&mut rand::rng())
+    }
+
+    /// Generates a new monotonic UUIDv7 using a custom random number
+    /// generator.
+    pub fn generate_with_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> Uuid7 {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards");
+        let mut millis = now.as_millis() as u64;
+
+        let mut state = self.state.lock().expect("Uuid7Generator mutex poisoned");
+
+        if millis > state.last_millis {
+            // Clock advanced: reseed the counter, leaving room to increment
+            // a few times before the next millisecond ticks over.
+            state.last_millis = millis;
+            state.counter = rng.random_range(0..=COUNTER_MAX / 2);
+        } else {
+            // Same millisecond (or the clock went backwards): keep
+            // advancing the counter so ordering stays strictly increasing.
+            millis = state.last_millis;
+            if state.counter < COUNTER_MAX {
+                state.counter += 1;
+            } else {
+                // Counter exhausted: borrow from the timestamp so we never
+                // emit a duplicate or out-of-order ID.
+                state.last_millis += 1;
+                millis = state.last_millis;
+                state.counter = 0;
+            }
+        }
+
+        Uuid7::from_parts_with_counter(millis, state.counter, rng)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for Uuid7Generator {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl fmt::Display for Uuid7 {
     /// Formats the UUIDv7 in the canonical form: 8-4-4-4-12 hexadecimal digits.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{:02x}{:02x}{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
-            self.bytes[0], self.bytes[1], self.bytes[2], self.bytes[3],
-            self.bytes[4], self.bytes[5],
-            self.bytes[6], self.bytes[7],
-            self.bytes[8], self.bytes[9],
-            self.bytes[10], self.bytes[11], self.bytes[12], self.bytes[13], self.bytes[14], self.bytes[15]
-        )
+        fmt::Display::fmt(&self.hyphenated(), f)
     }
 }
 
 /// Error type returned when parsing a UUIDv7 from a string fails.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct ParseUuid7Error(pub String);
+///
+/// Each variant pinpoints what was wrong and, where applicable, the byte
+/// index of the offending input so callers can build precise diagnostics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseUuid7Error {
+    /// The input's length didn't match any of the accepted string forms
+    /// (32, 36, 38, or 45 bytes), or, for [`Uuid7::from_slice`], the slice
+    /// was not exactly 16 bytes long.
+    InvalidLength {
+        /// A human-readable description of what was expected.
+        expected: &'static str,
+        /// The length that was actually found.
+        actual: usize,
+    },
+    /// A dash, brace, or `urn:uuid:` prefix was missing where one of the
+    /// accepted forms requires it.
+    InvalidGroupBoundary {
+        /// The byte index of the missing/unexpected boundary character.
+        index: usize,
+    },
+    /// A byte that should have been an ASCII hex digit was not.
+    InvalidHexDigit {
+        /// The byte index of the invalid character.
+        index: usize,
+        /// The invalid character itself.
+        found: char,
+    },
+    /// The version nibble was not `7`.
+    InvalidVersion {
+        /// The version nibble that was actually found.
+        found: u8,
+    },
+    /// The variant bits were not binary `10`.
+    InvalidVariant {
+        /// The two most-significant bits of the variant byte that were
+        /// actually found.
+        found: u8,
+    },
+}
 
 impl fmt::Display for ParseUuid7Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "ParseUuid7Error: {}", self.0)
+        match self {
+            ParseUuid7Error::InvalidLength { expected, actual } => write!(
+                f,
+                "invalid UUID length: expected {}, got {}",
+                expected, actual
+            ),
+            ParseUuid7Error::InvalidGroupBoundary { index } => {
+                write!(f, "invalid UUID format: expected a group boundary at index {}", index)
+            }
+            ParseUuid7Error::InvalidHexDigit { index, found } => {
+                write!(f, "invalid hex digit '{}' at index {}", found, index)
+            }
+            ParseUuid7Error::InvalidVersion { found } => {
+                write!(f, "invalid version: expected 7, got {}", found)
+            }
+            ParseUuid7Error::InvalidVariant { found } => {
+                write!(f, "invalid variant: expected binary 10xx, got {:#04b}", found)
+            }
+        }
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for ParseUuid7Error {}
 
-impl FromStr for Uuid7 {
-    type Err = ParseUuid7Error;
+/// Lookup table mapping an ASCII byte to its hex nibble value, or `-1` if
+/// it is not a valid hex digit.
+const fn build_hex_table() -> [i8; 256] {
+    let mut table = [-1i8; 256];
+    let mut i = 0u8;
+    while i < 10 {
+        table[(b'0' + i) as usize] = i as i8;
+        i += 1;
+    }
+    let mut i = 0u8;
+    while i < 6 {
+        table[(b'a' + i) as usize] = (10 + i) as i8;
+        table[(b'A' + i) as usize] = (10 + i) as i8;
+        i += 1;
+    }
+    table
+}
 
-    /// Parses a UUIDv7 from its canonical string representation.
-    ///
-    /// This method validates:
-    /// - The overall length and dash positions.
-    /// - That the version field is 7.
-    /// - That the variant field has its two most significant bits equal to binary `10`.
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // Check that the string has the correct length
-        if s.len() != 36 {
-            return Err(ParseUuid7Error("Invalid UUID length; expected 36 characters".into()));
-        }
-        
-        // Check dash positions
-        if s.chars().nth(8) != Some('-') || s.chars().nth(13) != Some('-') || 
-           s.chars().nth(18) != Some('-') || s.chars().nth(23) != Some('-') {
-            return Err(ParseUuid7Error("Invalid UUID format; expected dashes at positions 8, 13, 18, and 23".into()));
-        }
+const HEX_TABLE: [i8; 256] = build_hex_table();
+
+fn hex_nibble(byte: u8, index: usize) -> Result<u8, ParseUuid7Error> {
+    let value = HEX_TABLE[byte as usize];
+    if value < 0 {
+        Err(ParseUuid7Error::InvalidHexDigit {
+            index,
+            found: byte as char,
+        })
+    } else {
+        Ok(value as u8)
+    }
+}
+
+fn validate_version_variant(bytes: &[u8; 16]) -> Result<(), ParseUuid7Error> {
+    if bytes[6] & 0xF0 != 0x70 {
+        return Err(ParseUuid7Error::InvalidVersion { found: bytes[6] >> 4 });
+    }
+    if bytes[8] & 0xC0 != 0x80 {
+        return Err(ParseUuid7Error::InvalidVariant { found: bytes[8] >> 6 });
+    }
+    Ok(())
+}
+
+/// Decodes a dashless 32 hex-character UUID body, starting at `offset`
+/// within the original input (for error reporting).
+fn parse_simple(digits: &[u8], offset: usize) -> Result<Uuid7, ParseUuid7Error> {
+    let mut bytes = [0u8; 16];
+    for i in 0..16 {
+        let hi = hex_nibble(digits[i * 2], offset + i * 2)?;
+        let lo = hex_nibble(digits[i * 2 + 1], offset + i * 2 + 1)?;
+        bytes[i] = (hi << 4) | lo;
+    }
+    validate_version_variant(&bytes)?;
+    Ok(Uuid7 { bytes })
+}
 
-        // Check version (digit at position 14, should be 7)
-        if s.chars().nth(14) != Some('7') {
-            return Err(ParseUuid7Error(format!(
-                "Invalid version: expected 7, got {}",
-                s.chars().nth(14).unwrap_or('?')
-            )));
+/// Input-index ranges of the five hex segments in a 36-byte hyphenated
+/// body, i.e. everything *between* the 4 validated dash positions.
+const HYPHENATED_SEGMENTS: [(usize, usize); 5] = [(0, 8), (9, 13), (14, 18), (19, 23), (24, 36)];
+
+/// Decodes a hyphenated `8-4-4-4-12` UUID body (exactly 36 bytes), starting
+/// at `offset` within the original input (for error reporting).
+fn parse_hyphenated(group: &[u8], offset: usize) -> Result<Uuid7, ParseUuid7Error> {
+    const DASH_POSITIONS: [usize; 4] = [8, 13, 18, 23];
+    for &pos in &DASH_POSITIONS {
+        if group[pos] != b'-' {
+            return Err(ParseUuid7Error::InvalidGroupBoundary { index: offset + pos });
         }
+    }
 
-        // Check variant (digit at position 19, should be 8, 9, a, or b)
-        let variant_char = s.chars().nth(19).unwrap_or('?');
-        if !matches!(variant_char, '8' | '9' | 'a' | 'b' | 'A' | 'B') {
-            return Err(ParseUuid7Error(format!(
-                "Invalid variant: expected one of 8, 9, a, b, got {}",
-                variant_char
-            )));
+    // Only the 4 validated positions above are treated as separators; any
+    // other byte in these ranges must be a hex digit, including a stray
+    // '-' (which `hex_nibble` correctly rejects instead of letting it
+    // desync the pairing).
+    let mut bytes = [0u8; 16];
+    let mut out = 0;
+    for &(start, end) in &HYPHENATED_SEGMENTS {
+        let mut i = start;
+        while i < end {
+            let hi = hex_nibble(group[i], offset + i)?;
+            let lo = hex_nibble(group[i + 1], offset + i + 1)?;
+            bytes[out] = (hi << 4) | lo;
+            out += 1;
+            i += 2;
         }
+    }
 
-        // Remove dashes and parse the hex string
-        let hex: String = s.chars().filter(|&c| c != '-').collect();
-        
-        // Parse hex string into bytes
-        let mut bytes = [0u8; 16];
-        for i in 0..16 {
-            let byte_str = &hex[i*2..i*2+2];
-            bytes[i] = u8::from_str_radix(byte_str, 16)
-                .map_err(|_| ParseUuid7Error(format!("Invalid hex at position {}: {}", i, byte_str)))?;
+    validate_version_variant(&bytes)?;
+    Ok(Uuid7 { bytes })
+}
+
+impl FromStr for Uuid7 {
+    type Err = ParseUuid7Error;
+
+    /// Parses a UUIDv7 from any of its commonly accepted string forms:
+    /// the canonical hyphenated `8-4-4-4-12` form, the dashless 32-char
+    /// `simple` form, the `urn:uuid:`-prefixed form, or the `{braced}`
+    /// form. Validates that the version field is 7 and the variant field
+    /// is binary `10`. Parsing is a single allocation-free pass over the
+    /// input bytes.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const URN_PREFIX: &[u8; 9] = b"urn:uuid:";
+
+        let bytes = s.as_bytes();
+        match bytes.len() {
+            32 => parse_simple(bytes, 0),
+            36 => parse_hyphenated(bytes, 0),
+            38 => {
+                if bytes[0] != b'{' {
+                    return Err(ParseUuid7Error::InvalidGroupBoundary { index: 0 });
+                }
+                if bytes[37] != b'}' {
+                    return Err(ParseUuid7Error::InvalidGroupBoundary { index: 37 });
+                }
+                parse_hyphenated(&bytes[1..37], 1)
+            }
+            45 => {
+                if &bytes[..9] != URN_PREFIX {
+                    return Err(ParseUuid7Error::InvalidGroupBoundary { index: 0 });
+                }
+                parse_hyphenated(&bytes[9..45], 9)
+            }
+            other => Err(ParseUuid7Error::InvalidLength {
+                expected: "32, 36, 38, or 45 characters",
+                actual: other,
+            }),
         }
-        
-        Ok(Uuid7 { bytes })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::str::FromStr;
 
+    #[cfg(feature = "std")]
     fn check_canonical_format(s: &str) {
         // The canonical UUID string is 36 characters with dashes at positions 8, 13, 18, 23.
         assert_eq!(s.len(), 36);
@@ -205,6 +663,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_uuid7_new() {
         let uuid = Uuid7::new();
         let s = uuid.to_string();
@@ -231,6 +690,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_uuid7_from_str_valid() {
         // Create a valid UUIDv7 string using our generator.
         let uuid_orig = Uuid7::new();
@@ -245,7 +705,11 @@ mod tests {
     fn test_uuid7_from_str_invalid_length() {
         let s = "1234";
         let err = Uuid7::from_str(s).unwrap_err();
-        assert!(err.0.contains("Invalid"), "Error should mention invalid length");
+        assert!(
+            matches!(err, ParseUuid7Error::InvalidLength { actual: 4, .. }),
+            "Error should report an invalid length, got {:?}",
+            err
+        );
     }
 
     #[test]
@@ -253,10 +717,55 @@ mod tests {
         // Create a new UUID with a valid format but with version 1 instead of 7
         let s = "01234567-89ab-1def-8123-456789abcdef";
         let err = Uuid7::from_str(s).unwrap_err();
-        assert!(err.0.contains("Invalid version"), "Error should mention invalid version");
+        assert!(
+            matches!(err, ParseUuid7Error::InvalidVersion { found: 1 }),
+            "Error should report an invalid version, got {:?}",
+            err
+        );
     }
 
     #[test]
+    #[cfg(feature = "std")]
+    fn test_uuid7_from_str_accepts_simple_urn_and_braced_forms() {
+        let uuid = Uuid7::new();
+        let canonical = uuid.to_string();
+
+        let simple = canonical.replace('-', "");
+        assert_eq!(Uuid7::from_str(&simple).unwrap(), uuid);
+
+        let urn = format!("urn:uuid:{}", canonical);
+        assert_eq!(Uuid7::from_str(&urn).unwrap(), uuid);
+
+        let braced = format!("{{{}}}", canonical);
+        assert_eq!(Uuid7::from_str(&braced).unwrap(), uuid);
+    }
+
+    #[test]
+    fn test_uuid7_from_str_reports_hex_digit_index() {
+        let s = "0184e1a0-7e2a-7d40-8f3b-5c1a2b3c4dzz";
+        let err = Uuid7::from_str(s).unwrap_err();
+        assert!(matches!(
+            err,
+            ParseUuid7Error::InvalidHexDigit { index: 34, found: 'z' }
+        ));
+    }
+
+    #[test]
+    fn test_uuid7_from_str_rejects_misplaced_dash_without_panicking() {
+        // A stray '-' outside the 4 validated boundary positions must be
+        // rejected as an invalid hex digit, not desync the hex pairing and
+        // walk off the end of the buffer.
+        let s = "0184e1a0-7e2a-7d40-8f3b--c1a2b3c4d5e";
+        assert_eq!(s.len(), 36);
+        let err = Uuid7::from_str(s).unwrap_err();
+        assert!(matches!(
+            err,
+            ParseUuid7Error::InvalidHexDigit { index: 24, found: '-' }
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
     fn test_uuid7_uniqueness() {
         let uuid1 = Uuid7::new();
         let uuid2 = Uuid7::new();
@@ -264,6 +773,7 @@ mod tests {
     }
     
     #[test]
+    #[cfg(feature = "std")]
     fn test_roundtrip_as_u128() {
         let uuid = Uuid7::new();
         let value = uuid.as_u128();
@@ -276,4 +786,150 @@ mod tests {
             assert_eq!(expected, actual, "Byte at position {} doesn't match", i);
         }
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_timestamp_roundtrip() {
+        let mut rng = rand::rng();
+        let millis = 1_718_000_000_123;
+        let uuid = Uuid7::new_with_timestamp(millis, &mut rng);
+        assert_eq!(uuid.timestamp_millis(), millis);
+        assert_eq!(
+            uuid.timestamp(),
+            UNIX_EPOCH + std::time::Duration::from_millis(millis)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_new_with_system_time_matches_new_with_timestamp() {
+        let mut rng = rand::rng();
+        let time = UNIX_EPOCH + std::time::Duration::from_millis(1_718_000_000_123);
+        let uuid = Uuid7::new_with_system_time(time, &mut rng);
+        assert_eq!(uuid.timestamp_millis(), 1_718_000_000_123);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_bytes_and_as_bytes_roundtrip() {
+        let uuid = Uuid7::new();
+        let rebuilt = Uuid7::from_bytes(*uuid.as_bytes());
+        assert_eq!(uuid, rebuilt);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_u128_and_as_u128_roundtrip() {
+        let uuid = Uuid7::new();
+        let rebuilt = Uuid7::from_u128(uuid.as_u128());
+        assert_eq!(uuid, rebuilt);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_slice_valid_and_invalid_length() {
+        let uuid = Uuid7::new();
+        let rebuilt = Uuid7::from_slice(uuid.as_bytes().as_slice()).expect("16 bytes should parse");
+        assert_eq!(uuid, rebuilt);
+
+        let err = Uuid7::from_slice(&[0u8; 15]).unwrap_err();
+        assert!(matches!(
+            err,
+            ParseUuid7Error::InvalidLength { actual: 15, .. }
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_try_from_bytes_rejects_bad_version_and_variant() {
+        let mut bytes = *Uuid7::new().as_bytes();
+        bytes[6] = (bytes[6] & 0x0F) | 0x10; // version 1 instead of 7
+        assert!(Uuid7::try_from_bytes(bytes).is_err());
+
+        let mut bytes = *Uuid7::new().as_bytes();
+        bytes[8] &= 0x3F; // clear the variant bits entirely
+        assert!(Uuid7::try_from_bytes(bytes).is_err());
+    }
+
+    #[test]
+    fn test_nil_and_max_constants() {
+        assert!(Uuid7::nil().is_nil());
+        assert_eq!(Uuid7::nil().as_bytes(), &[0u8; 16]);
+        assert!(!Uuid7::max().is_nil());
+        assert_eq!(Uuid7::max().as_bytes(), &[0xffu8; 16]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_formatting_adapters_match_display() {
+        let uuid = Uuid7::new();
+        let display = uuid.to_string();
+
+        let mut buf = [0u8; Hyphenated::LENGTH];
+        assert_eq!(&*uuid.hyphenated().encode_lower(&mut buf), display);
+
+        let mut buf = [0u8; Simple::LENGTH];
+        assert_eq!(
+            &*uuid.simple().encode_lower(&mut buf),
+            display.replace('-', "")
+        );
+
+        let mut buf = [0u8; Urn::LENGTH];
+        assert_eq!(
+            &*uuid.urn().encode_lower(&mut buf),
+            format!("urn:uuid:{}", display)
+        );
+
+        let mut buf = [0u8; Braced::LENGTH];
+        assert_eq!(
+            &*uuid.braced().encode_lower(&mut buf),
+            format!("{{{}}}", display)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_formatting_adapters_uppercase() {
+        let uuid = Uuid7::new();
+        let mut buf = [0u8; Hyphenated::LENGTH];
+        let upper = uuid.hyphenated().encode_upper(&mut buf).to_string();
+        assert_eq!(upper, upper.to_uppercase());
+        assert_eq!(upper.to_lowercase(), uuid.to_string());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_generator_monotonic_same_millisecond() {
+        let generator = Uuid7Generator::new();
+        let mut previous = generator.generate();
+        for _ in 0..1000 {
+            let next = generator.generate();
+            assert!(next > previous, "generator output must be strictly increasing");
+            previous = next;
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_generator_counter_overflow_borrows_from_timestamp() {
+        let generator = Uuid7Generator::new();
+        let now_millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        {
+            let mut state = generator.state.lock().unwrap();
+            state.last_millis = now_millis;
+            state.counter = COUNTER_MAX;
+        }
+        let mut rng = rand::rng();
+        let uuid = generator.generate_with_rng(&mut rng);
+        let bytes = uuid.as_bytes();
+        let millis = (0..6).fold(0u64, |acc, i| (acc << 8) | bytes[i] as u64);
+        assert_eq!(
+            millis,
+            now_millis + 1,
+            "counter overflow should bump the millisecond"
+        );
+    }
 }
\ No newline at end of file
@@ -0,0 +1,149 @@
+//! Optional [`serde`] support for [`Uuid7`], enabled via the `serde`
+//! feature.
+//!
+//! By default, `Uuid7` serializes to and deserializes from its canonical
+//! hyphenated string, accepting either a string or a 16-byte sequence on
+//! the way in. For binary formats where the 36-char string wastes space
+//! (bincode, MessagePack, ...), use the [`compact`] module with
+//! `#[serde(with = "leachuuid7::compact")]` to serialize as a `[u8; 16]`
+//! instead.
+
+use crate::Uuid7;
+use core::fmt;
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl Serialize for Uuid7 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+struct Uuid7Visitor;
+
+impl<'de> Visitor<'de> for Uuid7Visitor {
+    type Value = Uuid7;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a UUIDv7 string or a 16-byte sequence")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        v.parse().map_err(de::Error::custom)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let bytes: [u8; 16] = v
+            .try_into()
+            .map_err(|_| de::Error::invalid_length(v.len(), &self))?;
+        Uuid7::try_from_bytes(bytes).map_err(de::Error::custom)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let mut bytes = [0u8; 16];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        Uuid7::try_from_bytes(bytes).map_err(de::Error::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for Uuid7 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(Uuid7Visitor)
+    }
+}
+
+/// A `serde(with = "...")` module that serializes a [`Uuid7`] as its raw
+/// `[u8; 16]` representation instead of the canonical string.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use leachuuid7::Uuid7;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Row {
+///     #[serde(with = "leachuuid7::compact")]
+///     id: Uuid7,
+/// }
+/// ```
+pub mod compact {
+    use super::{de, Uuid7};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S>(uuid: &Uuid7, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        uuid.as_bytes().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Uuid7, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = <[u8; 16]>::deserialize(deserializer)?;
+        Uuid7::try_from_bytes(bytes).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    fn sample_uuid() -> Uuid7 {
+        let mut rng = SmallRng::seed_from_u64(42);
+        Uuid7::new_with_timestamp(1_718_000_000_000, &mut rng)
+    }
+
+    #[test]
+    fn test_serde_json_roundtrip_via_string() {
+        let uuid = sample_uuid();
+        let json = serde_json::to_string(&uuid).unwrap();
+
+        let mut buf = [0u8; crate::Hyphenated::LENGTH];
+        let expected = uuid.hyphenated().encode_lower(&mut buf);
+        assert_eq!(&json[1..json.len() - 1], &*expected);
+        assert!(json.starts_with('"') && json.ends_with('"'));
+
+        let back: Uuid7 = serde_json::from_str(&json).unwrap();
+        assert_eq!(uuid, back);
+    }
+
+    #[test]
+    fn test_serde_compact_roundtrip_via_bytes() {
+        #[derive(Serialize, Deserialize)]
+        struct Row {
+            #[serde(with = "crate::compact")]
+            id: Uuid7,
+        }
+
+        let row = Row { id: sample_uuid() };
+        let bytes = bincode::serialize(&row).unwrap();
+        assert_eq!(bytes.len(), 16);
+
+        let back: Row = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(row.id, back.id);
+    }
+}